@@ -0,0 +1,45 @@
+//! Command-line configuration.
+
+use std::time::Duration;
+
+use clap::Parser;
+
+/// A terminal network traffic monitor.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "netstats", about = "A terminal network traffic monitor")]
+pub struct Config {
+    /// Name of the capture interface to use (defaults to pcap's lookup device)
+    #[arg(short, long)]
+    pub interface: Option<String>,
+
+    /// BPF filter expression applied to the capture, e.g. "tcp or udp"
+    #[arg(short, long)]
+    pub filter: Option<String>,
+
+    /// Capture in promiscuous mode
+    #[arg(short, long, default_value_t = true, action = clap::ArgAction::Set)]
+    pub promiscuous: bool,
+
+    /// Width, in seconds, of the rolling time window plotted on each chart
+    #[arg(short, long, default_value_t = 60)]
+    pub window_size: usize,
+
+    /// UI tick/refresh rate, in milliseconds
+    #[arg(short, long, default_value_t = 100)]
+    pub tick_rate_ms: u64,
+
+    /// Render inline below the current prompt instead of taking over the
+    /// full screen, so netstats can be used as part of a scripted pipeline
+    #[arg(long)]
+    pub inline: bool,
+
+    /// Height, in rows, of the inline viewport (only used with --inline)
+    #[arg(long, default_value_t = 20)]
+    pub inline_height: u16,
+}
+
+impl Config {
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_millis(self.tick_rate_ms)
+    }
+}
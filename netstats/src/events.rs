@@ -0,0 +1,65 @@
+//! Channel-based event loop: the capture thread and the timer/input thread
+//! only ever produce events, never touch `AppState` directly, so the UI
+//! thread is the sole owner and mutator of state.
+
+use std::net::IpAddr;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event as CrosstermEvent, KeyEvent};
+
+use crate::parse::{Direction, Protocol};
+
+/// Metadata for a single captured packet, handed off by the capture thread.
+#[derive(Debug, Clone, Copy)]
+pub struct PacketMeta {
+    pub size: usize,
+    pub direction: Option<Direction>,
+    pub protocol: Protocol,
+    pub src_ip: Option<IpAddr>,
+    pub dst_ip: Option<IpAddr>,
+}
+
+/// Everything the UI thread can react to.
+pub enum Event {
+    Packet(PacketMeta),
+    Tick,
+    Input(KeyEvent),
+    Resize,
+}
+
+/// Spawn the timer/input thread: forwards crossterm key and resize events as
+/// they arrive, and fires `Event::Tick` whenever `tick_rate` elapses without
+/// one, so throughput can be computed on a predictable cadence.
+pub fn spawn_input_and_tick(tx: Sender<Event>, tick_rate: Duration) {
+    thread::spawn(move || {
+        let mut last_tick = Instant::now();
+        loop {
+            let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+
+            if event::poll(timeout).unwrap_or(false) {
+                match event::read() {
+                    Ok(CrosstermEvent::Key(key)) => {
+                        if tx.send(Event::Input(key)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(CrosstermEvent::Resize(_, _)) => {
+                        if tx.send(Event::Resize).is_err() {
+                            return;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if last_tick.elapsed() >= tick_rate {
+                if tx.send(Event::Tick).is_err() {
+                    return;
+                }
+                last_tick = Instant::now();
+            }
+        }
+    });
+}
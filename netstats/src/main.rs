@@ -1,42 +1,85 @@
+use clap::Parser;
 use pcap::{Capture, Device};
+use std::net::IpAddr;
+use std::sync::mpsc;
 use std::thread;
+
+mod config;
+mod events;
+mod parse;
 mod tui_plotter;
+
+use config::Config;
+use events::{Event, PacketMeta, spawn_input_and_tick};
+use parse::inspect_packet;
 use tui_plotter::NetworkPlotter;
 
-fn main() {
-    // Create the network plotter
-    let plotter = NetworkPlotter::new();
-    let plotter_clone = plotter.get_state();
+/// Select the requested capture device by name, falling back to pcap's
+/// lookup device when none was requested. Exits after listing the
+/// available interfaces if the requested one doesn't exist.
+fn select_device(requested: Option<&str>) -> Device {
+    let Some(name) = requested else {
+        return Device::lookup().unwrap().unwrap();
+    };
 
-    // Start the TUI in a separate thread
-    let tui_thread = thread::spawn(move || {
-        plotter.start_ui().unwrap();
-    });
+    let devices = Device::list().expect("failed to list capture devices");
+    if let Some(device) = devices.iter().find(|d| d.name == name) {
+        return device.clone();
+    }
+
+    eprintln!("No such interface: {name}");
+    eprintln!("Available interfaces:");
+    for device in &devices {
+        eprintln!("  {}", device.name);
+    }
+    std::process::exit(1);
+}
+
+fn main() {
+    let config = Config::parse();
+    let (tx, rx) = mpsc::channel::<Event>();
 
     // Set up pcap
-    let device = Device::lookup().unwrap().unwrap();
+    let device = select_device(config.interface.as_deref());
+    let own_addrs: Vec<IpAddr> = device.addresses.iter().map(|addr| addr.addr).collect();
     let mut cap = Capture::from_device(device)
         .unwrap()
-        .promisc(true)
+        .promisc(config.promiscuous)
         .open()
         .unwrap();
 
-    while let Ok(packet) = cap.next_packet() {
-        let packet_size = packet.data.len();
+    if let Some(filter) = &config.filter {
+        cap.filter(filter, true).unwrap();
+    }
+
+    // Capture thread: only ever produces events, never touches AppState
+    let capture_tx = tx.clone();
+    thread::spawn(move || {
+        while let Ok(packet) = cap.next_packet() {
+            let size = packet.data.len();
+            let info = inspect_packet(packet.data, &own_addrs);
 
-        // Update the plotter with the new packet data
-        if let Ok(mut state) = plotter_clone.lock() {
-            state.update_stats(packet_size);
+            let meta = PacketMeta {
+                size,
+                direction: info.direction,
+                protocol: info.protocol,
+                src_ip: info.src_ip,
+                dst_ip: info.dst_ip,
+            };
 
-            // Check if the TUI is still running
-            if !state.running {
+            if capture_tx.send(Event::Packet(meta)).is_err() {
                 break;
             }
         }
-    }
+    });
 
-    if let Err(e) = tui_thread.join() {
-        eprintln!("TUI thread panicked: {:?}", e);
+    // Timer/input thread: drives ticks and forwards crossterm key events
+    spawn_input_and_tick(tx, config.tick_rate());
+
+    // The UI thread (this one) owns AppState exclusively and mutates it only
+    // in response to events received over the channel.
+    let plotter = NetworkPlotter::new(config.window_size, config.inline, config.inline_height);
+    if let Err(e) = plotter.start_ui(rx) {
+        eprintln!("TUI error: {:?}", e);
     }
 }
-
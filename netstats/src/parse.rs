@@ -0,0 +1,150 @@
+//! Minimal link-layer/IP/transport-layer parsing used to classify a
+//! captured packet's direction and protocol for the live stats views.
+
+use std::net::IpAddr;
+
+const ETHERNET_HEADER_LEN: usize = 14;
+const ETHERTYPE_IPV4: [u8; 2] = [0x08, 0x00];
+const ETHERTYPE_IPV6: [u8; 2] = [0x86, 0xDD];
+const ETHERTYPE_ARP: [u8; 2] = [0x08, 0x06];
+
+const IP_PROTO_ICMP: u8 = 1;
+const IP_PROTO_TCP: u8 = 6;
+const IP_PROTO_UDP: u8 = 17;
+
+/// Direction of a captured packet relative to the local capture device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Rx,
+    Tx,
+}
+
+/// Transport/network-layer protocol a packet was classified as, for the
+/// per-protocol breakdown table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Icmp,
+    Arp,
+    Other,
+}
+
+impl Protocol {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+            Protocol::Icmp => "ICMP",
+            Protocol::Arp => "ARP",
+            Protocol::Other => "Other",
+        }
+    }
+}
+
+/// Everything `inspect_packet` can determine about a single captured frame.
+#[derive(Debug, Clone, Copy)]
+pub struct PacketInfo {
+    pub direction: Option<Direction>,
+    pub protocol: Protocol,
+    pub src_ip: Option<IpAddr>,
+    pub dst_ip: Option<IpAddr>,
+}
+
+impl PacketInfo {
+    fn unknown() -> Self {
+        Self {
+            direction: None,
+            protocol: Protocol::Other,
+            src_ip: None,
+            dst_ip: None,
+        }
+    }
+}
+
+struct IpHeaderInfo {
+    src: IpAddr,
+    dst: IpAddr,
+    protocol: u8,
+}
+
+fn parse_ipv4(data: &[u8]) -> Option<IpHeaderInfo> {
+    if data.len() < 20 {
+        return None;
+    }
+    Some(IpHeaderInfo {
+        src: IpAddr::from([data[12], data[13], data[14], data[15]]),
+        dst: IpAddr::from([data[16], data[17], data[18], data[19]]),
+        protocol: data[9],
+    })
+}
+
+fn parse_ipv6(data: &[u8]) -> Option<IpHeaderInfo> {
+    if data.len() < 40 {
+        return None;
+    }
+    let mut src = [0u8; 16];
+    let mut dst = [0u8; 16];
+    src.copy_from_slice(&data[8..24]);
+    dst.copy_from_slice(&data[24..40]);
+    Some(IpHeaderInfo {
+        src: IpAddr::from(src),
+        dst: IpAddr::from(dst),
+        protocol: data[6], // next header
+    })
+}
+
+fn protocol_from_ip(proto: u8) -> Protocol {
+    match proto {
+        IP_PROTO_TCP => Protocol::Tcp,
+        IP_PROTO_UDP => Protocol::Udp,
+        IP_PROTO_ICMP => Protocol::Icmp,
+        _ => Protocol::Other,
+    }
+}
+
+/// Parse a captured Ethernet frame down to the transport layer, classifying
+/// its direction (by comparing its IP addresses against `own_addrs`) and its
+/// protocol (TCP/UDP/ICMP/ARP/other).
+pub fn inspect_packet(frame: &[u8], own_addrs: &[IpAddr]) -> PacketInfo {
+    if frame.len() < ETHERNET_HEADER_LEN {
+        return PacketInfo::unknown();
+    }
+
+    let ethertype = &frame[12..14];
+    let payload = &frame[ETHERNET_HEADER_LEN..];
+
+    if ethertype == ETHERTYPE_ARP {
+        return PacketInfo {
+            protocol: Protocol::Arp,
+            ..PacketInfo::unknown()
+        };
+    }
+
+    let ip_info = if ethertype == ETHERTYPE_IPV4 {
+        parse_ipv4(payload)
+    } else if ethertype == ETHERTYPE_IPV6 {
+        parse_ipv6(payload)
+    } else {
+        None
+    };
+
+    let Some(ip_info) = ip_info else {
+        return PacketInfo::unknown();
+    };
+
+    let direction = if own_addrs.contains(&ip_info.src) {
+        Some(Direction::Tx)
+    } else if own_addrs.contains(&ip_info.dst) {
+        Some(Direction::Rx)
+    } else {
+        None
+    };
+
+    PacketInfo {
+        direction,
+        protocol: protocol_from_ip(ip_info.protocol),
+        src_ip: Some(ip_info.src),
+        dst_ip: Some(ip_info.dst),
+    }
+}
@@ -1,37 +1,50 @@
 #![allow(dead_code)]
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::io;
-use std::sync::{Arc, Mutex};
+use std::net::IpAddr;
+use std::sync::mpsc::Receiver;
 use std::time::{Duration, Instant};
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyCode},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
-    Frame, Terminal,
+    Frame, Terminal, TerminalOptions, Viewport,
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph, Row, Table},
 };
 
+use crate::events::Event as AppEvent;
+use crate::parse::Direction as PacketDirection;
+use crate::parse::Protocol;
+
+/// How far back, in seconds, the rolling throughput window looks when
+/// computing the current bytes/sec rate.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(5);
+
 pub struct NetworkStats {
     pub total_bytes: usize,
-    pub current_throughput: usize,
+    pub current_throughput: f64, // bytes/sec, averaged over THROUGHPUT_WINDOW
     pub packets_captured: usize,
+    pub total_rx: usize,
+    pub total_tx: usize,
 }
 
 impl Default for NetworkStats {
     fn default() -> Self {
         Self {
             total_bytes: 0,
-            current_throughput: 0,
+            current_throughput: 0.0,
             packets_captured: 0,
+            total_rx: 0,
+            total_tx: 0,
         }
     }
 }
@@ -41,10 +54,47 @@ pub struct DataPoint {
     pub value: f64,
 }
 
+/// Live per-protocol packet/byte tallies, plus a top-talkers map keyed by
+/// source/destination IP, fed by `parse::inspect_packet`.
+#[derive(Default)]
+pub struct ProtocolStats {
+    pub counts: HashMap<Protocol, (usize, usize)>, // protocol -> (packets, bytes)
+    pub top_talkers: HashMap<IpAddr, usize>,        // ip -> bytes seen as src or dst
+}
+
+impl ProtocolStats {
+    pub fn record(
+        &mut self,
+        protocol: Protocol,
+        size: usize,
+        src_ip: Option<IpAddr>,
+        dst_ip: Option<IpAddr>,
+    ) {
+        let entry = self.counts.entry(protocol).or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += size;
+
+        if let Some(ip) = src_ip {
+            *self.top_talkers.entry(ip).or_insert(0) += size;
+        }
+        if let Some(ip) = dst_ip {
+            *self.top_talkers.entry(ip).or_insert(0) += size;
+        }
+    }
+
+    pub fn total_packets(&self) -> usize {
+        self.counts.values().map(|(packets, _)| packets).sum()
+    }
+}
+
 pub struct AppState {
     pub stats: NetworkStats,
+    pub protocol_stats: ProtocolStats,
     pub throughput_history: VecDeque<DataPoint>,
     pub packet_size_history: VecDeque<DataPoint>,
+    pub rx_history: VecDeque<DataPoint>,
+    pub tx_history: VecDeque<DataPoint>,
+    byte_events: VecDeque<(Instant, usize)>,
     pub start_time: Instant,
     pub last_update: Instant,
     pub running: bool,
@@ -55,8 +105,12 @@ impl Default for AppState {
     fn default() -> Self {
         Self {
             stats: NetworkStats::default(),
+            protocol_stats: ProtocolStats::default(),
             throughput_history: VecDeque::with_capacity(100),
             packet_size_history: VecDeque::with_capacity(100),
+            rx_history: VecDeque::with_capacity(100),
+            tx_history: VecDeque::with_capacity(100),
+            byte_events: VecDeque::new(),
             start_time: Instant::now(),
             last_update: Instant::now(),
             running: true,
@@ -65,24 +119,44 @@ impl Default for AppState {
     }
 }
 
+/// Drop samples older than the plotted window, but always leave one sample
+/// older than `x_min` in place so `interpolate_left_edge` has a real point to
+/// interpolate the left edge from instead of falling back to an unmodified,
+/// gapped series.
+fn prune_to_window(history: &mut VecDeque<DataPoint>, x_min: f64) {
+    while history.len() >= 2 && history[1].time < x_min {
+        history.pop_front();
+    }
+}
+
 impl AppState {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window_size,
+            ..Self::default()
+        }
     }
 
-    pub fn update_stats(&mut self, packet_size: usize) {
+    /// Fold a captured packet into the running stats and history series.
+    /// Throughput is intentionally NOT derived here — see `tick` — so the
+    /// rate is sampled on a predictable cadence rather than once per packet.
+    pub fn record_packet(
+        &mut self,
+        packet_size: usize,
+        direction: Option<PacketDirection>,
+        protocol: Protocol,
+        src_ip: Option<IpAddr>,
+        dst_ip: Option<IpAddr>,
+    ) {
         self.stats.total_bytes += packet_size;
         self.stats.packets_captured += 1;
-        self.stats.current_throughput = packet_size;
+        self.protocol_stats
+            .record(protocol, packet_size, src_ip, dst_ip);
 
         let now = Instant::now();
         let elapsed = now.duration_since(self.start_time).as_secs_f64();
 
-        // Add throughput data point
-        self.throughput_history.push_back(DataPoint {
-            time: elapsed,
-            value: (packet_size as f64) / 1024.0, // KB
-        });
+        self.byte_events.push_back((now, packet_size));
 
         // Add packet size data point
         self.packet_size_history.push_back(DataPoint {
@@ -90,57 +164,123 @@ impl AppState {
             value: packet_size as f64,
         });
 
-        // Maintain window size
-        while self.throughput_history.len() > self.window_size {
-            self.throughput_history.pop_front();
+        // Add the directional data point, when the packet could be classified
+        match direction {
+            Some(PacketDirection::Rx) => {
+                self.stats.total_rx += packet_size;
+                self.rx_history.push_back(DataPoint {
+                    time: elapsed,
+                    value: packet_size as f64,
+                });
+            }
+            Some(PacketDirection::Tx) => {
+                self.stats.total_tx += packet_size;
+                self.tx_history.push_back(DataPoint {
+                    time: elapsed,
+                    value: packet_size as f64,
+                });
+            }
+            None => {}
         }
 
-        while self.packet_size_history.len() > self.window_size {
-            self.packet_size_history.pop_front();
-        }
+        // Trim histories to the window, in seconds, that the charts plot —
+        // keeping one sample older than the boundary for left-edge
+        // interpolation (see `prune_to_window` and `interpolate_left_edge`).
+        let x_min = (elapsed - self.window_size as f64).max(0.0);
+        prune_to_window(&mut self.packet_size_history, x_min);
+        prune_to_window(&mut self.rx_history, x_min);
+        prune_to_window(&mut self.tx_history, x_min);
 
         self.last_update = now;
     }
+
+    /// Deterministically derive the current throughput rate from the rolling
+    /// byte-event window and record it, driven by `Event::Tick` rather than
+    /// by packet arrival.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.start_time).as_secs_f64();
+
+        while let Some((event_time, _)) = self.byte_events.front() {
+            if now.duration_since(*event_time) > THROUGHPUT_WINDOW {
+                self.byte_events.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        // Divide by the nominal window (or, during the first
+        // THROUGHPUT_WINDOW after start, by the time elapsed so far), not by
+        // the span the retained events happen to cover — after an idle gap
+        // that span is much smaller than the window, which would otherwise
+        // inflate the rate instead of reporting a true rolling average.
+        let window_bytes: usize = self.byte_events.iter().map(|(_, size)| size).sum();
+        let window_secs = THROUGHPUT_WINDOW
+            .min(now.duration_since(self.start_time))
+            .as_secs_f64()
+            .max(1.0 / 1000.0); // avoid dividing by a near-zero window at startup
+        self.stats.current_throughput = window_bytes as f64 / window_secs;
+
+        self.throughput_history.push_back(DataPoint {
+            time: elapsed,
+            value: self.stats.current_throughput / 1024.0, // KB/s
+        });
+
+        // `window_size` is a seconds span (see the chart x-axes), so prune on
+        // that basis rather than by sample count.
+        let x_min = (elapsed - self.window_size as f64).max(0.0);
+        prune_to_window(&mut self.throughput_history, x_min);
+    }
 }
 
 pub struct NetworkPlotter {
-    state: Arc<Mutex<AppState>>,
+    state: AppState,
+    inline: bool,
+    inline_height: u16,
 }
 
 impl NetworkPlotter {
-    pub fn new() -> Self {
+    pub fn new(window_size: usize, inline: bool, inline_height: u16) -> Self {
         Self {
-            state: Arc::new(Mutex::new(AppState::new())),
-        }
-    }
-
-    pub fn get_state(&self) -> Arc<Mutex<AppState>> {
-        Arc::clone(&self.state)
-    }
-
-    pub fn update(&self, packet_size: usize) {
-        if let Ok(mut state) = self.state.lock() {
-            state.update_stats(packet_size);
+            state: AppState::new(window_size),
+            inline,
+            inline_height,
         }
     }
 
-    pub fn start_ui(self) -> io::Result<()> {
+    pub fn start_ui(self, rx: Receiver<AppEvent>) -> io::Result<()> {
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
 
-        let res = self.run_app(&mut terminal);
+        let inline = self.inline;
+        let mut terminal = if inline {
+            // Draw in a fixed-height region below the prompt instead of
+            // wiping the screen, so netstats can sit in a scripted pipeline.
+            let backend = CrosstermBackend::new(stdout);
+            Terminal::with_options(
+                backend,
+                TerminalOptions {
+                    viewport: Viewport::Inline(self.inline_height),
+                },
+            )?
+        } else {
+            execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+            let backend = CrosstermBackend::new(stdout);
+            Terminal::new(backend)?
+        };
+
+        let res = self.run_app(&mut terminal, rx);
 
         // Restore terminal
         disable_raw_mode()?;
-        execute!(
-            terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        )?;
+        if !inline {
+            execute!(
+                terminal.backend_mut(),
+                LeaveAlternateScreen,
+                DisableMouseCapture
+            )?;
+        }
         terminal.show_cursor()?;
 
         if let Err(err) = res {
@@ -150,49 +290,70 @@ impl NetworkPlotter {
         Ok(())
     }
 
-    fn run_app<B: Backend>(&self, terminal: &mut Terminal<B>) -> io::Result<()> {
-        let state_clone = Arc::clone(&self.state);
+    fn run_app<B: Backend>(
+        mut self,
+        terminal: &mut Terminal<B>,
+        rx: Receiver<AppEvent>,
+    ) -> io::Result<()> {
+        // Draw the first frame before waiting on any event so the screen
+        // isn't blank while the capture thread warms up.
+        terminal.draw(|f| self.ui(f))?;
 
         loop {
-            terminal.draw(|f| self.ui(f, &state_clone))?;
-
-            if event::poll(Duration::from_millis(100))? {
-                if let Event::Key(key) = event::read()? {
-                    if let Ok(mut state) = state_clone.lock() {
-                        match key.code {
-                            KeyCode::Char('q') => {
-                                state.running = false;
-                                return Ok(());
-                            }
-                            KeyCode::Char('+') => {
-                                state.window_size = state.window_size.saturating_add(10);
-                            }
-                            KeyCode::Char('-') => {
-                                state.window_size = state.window_size.saturating_sub(10).max(10);
-                            }
-                            _ => {}
+            // Packets arrive far more often than the UI needs to repaint, so
+            // only redraw in response to the low-frequency events below —
+            // otherwise a busy interface turns into a full-TUI redraw storm.
+            let should_draw = match rx.recv() {
+                Ok(AppEvent::Packet(meta)) => {
+                    self.state.record_packet(
+                        meta.size,
+                        meta.direction,
+                        meta.protocol,
+                        meta.src_ip,
+                        meta.dst_ip,
+                    );
+                    false
+                }
+                Ok(AppEvent::Tick) => {
+                    self.state.tick();
+                    true
+                }
+                Ok(AppEvent::Input(key)) => {
+                    match key.code {
+                        KeyCode::Char('q') => {
+                            self.state.running = false;
+                        }
+                        KeyCode::Char('+') => {
+                            self.state.window_size = self.state.window_size.saturating_add(10);
+                        }
+                        KeyCode::Char('-') => {
+                            self.state.window_size =
+                                self.state.window_size.saturating_sub(10).max(10);
                         }
+                        _ => {}
                     }
+                    true
                 }
+                Ok(AppEvent::Resize) => true,
+                // The capture and timer/input threads are gone; nothing left to drive the UI.
+                Err(_) => break,
+            };
+
+            if !self.state.running {
+                break;
             }
 
-            if let Ok(state) = state_clone.lock() {
-                if !state.running {
-                    break;
-                }
+            if should_draw {
+                terminal.draw(|f| self.ui(f))?;
             }
         }
 
         Ok(())
     }
 
-    fn ui<B: Backend>(&self, f: &mut Frame<B>, state_arc: &Arc<Mutex<AppState>>) {
+    fn ui<B: Backend>(&self, f: &mut Frame<B>) {
         let size = f.size();
-
-        // Lock state only when needed to minimize contention
-        let Ok(state) = state_arc.lock() else {
-            return;
-        };
+        let state = &self.state;
 
         // Create the layout
         let chunks = Layout::default()
@@ -236,17 +397,20 @@ impl NetworkPlotter {
 
         let top_chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+            .constraints(
+                [
+                    Constraint::Ratio(1, 5),
+                    Constraint::Ratio(1, 5),
+                    Constraint::Ratio(1, 5),
+                    Constraint::Ratio(1, 5),
+                    Constraint::Ratio(1, 5),
+                ]
+                .as_ref(),
+            )
             .split(chunks[0]);
 
         // Total bytes captured with enhanced styling
-        let total_bytes = state.stats.total_bytes as f64;
-        let (size_value, size_unit) = if total_bytes >= 1024.0 * 1024.0 * 1024.0 {
-            (total_bytes / (1024.0 * 1024.0 * 1024.0), "GB")
-        } else {
-            (total_bytes / (1024.0 * 1024.0), "MB")
-        };
-
+        let (size_value, size_unit) = format_bytes(state.stats.total_bytes as f64);
         let total_bytes_text = vec![Line::from(vec![
             Span::styled("Total Data Captured: ", Style::default().fg(Color::Green)),
             Span::styled(
@@ -269,6 +433,54 @@ impl NetworkPlotter {
         );
         f.render_widget(total_bytes_paragraph, top_chunks[0]);
 
+        // RX total with enhanced styling
+        let (rx_value, rx_unit) = format_bytes(state.stats.total_rx as f64);
+        let rx_text = vec![Line::from(vec![
+            Span::styled("Received: ", Style::default().fg(Color::Green)),
+            Span::styled(
+                format!("{:.2} {}", rx_value, rx_unit),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])];
+        let rx_paragraph = Paragraph::new(rx_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::LightGreen))
+                .title(Span::styled(
+                    "RX",
+                    Style::default()
+                        .fg(Color::LightGreen)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+        f.render_widget(rx_paragraph, top_chunks[1]);
+
+        // TX total with enhanced styling
+        let (tx_value, tx_unit) = format_bytes(state.stats.total_tx as f64);
+        let tx_text = vec![Line::from(vec![
+            Span::styled("Transmitted: ", Style::default().fg(Color::Red)),
+            Span::styled(
+                format!("{:.2} {}", tx_value, tx_unit),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])];
+        let tx_paragraph = Paragraph::new(tx_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::LightRed))
+                .title(Span::styled(
+                    "TX",
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+        f.render_widget(tx_paragraph, top_chunks[2]);
+
         // Packets captured with enhanced styling
         let packets_text = vec![Line::from(vec![
             Span::styled("Packets Captured: ", Style::default().fg(Color::Magenta)),
@@ -290,10 +502,61 @@ impl NetworkPlotter {
                         .add_modifier(Modifier::BOLD),
                 )),
         );
-        f.render_widget(packets_paragraph, top_chunks[1]);
+        f.render_widget(packets_paragraph, top_chunks[3]);
+
+        // Current throughput rate with enhanced styling
+        let (rate_value, rate_unit) = format_rate(state.stats.current_throughput);
+        let throughput_text = vec![Line::from(vec![
+            Span::styled("Throughput: ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                format!("{:.2} {}", rate_value, rate_unit),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])];
+        let throughput_paragraph = Paragraph::new(throughput_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(Span::styled(
+                    "Throughput",
+                    Style::default()
+                        .fg(Color::LightYellow)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+        f.render_widget(throughput_paragraph, top_chunks[4]);
 
-        // Render the packet size chart at the bottom
-        self.render_packet_size_chart(f, chunks[1], state);
+        // Split the lower area into the charts and the protocol breakdown pane
+        let lower_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)].as_ref())
+            .split(chunks[1]);
+
+        let chart_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                ]
+                .as_ref(),
+            )
+            .split(lower_chunks[0]);
+
+        self.render_packet_size_chart(f, chart_chunks[0], state);
+        self.render_traffic_chart(f, chart_chunks[1], state);
+        self.render_throughput_chart(f, chart_chunks[2], state);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+            .split(lower_chunks[1]);
+
+        self.render_protocol_table(f, side_chunks[0], state);
+        self.render_top_talkers(f, side_chunks[1], state);
     }
 
     fn render_packet_size_chart<B: Backend>(&self, f: &mut Frame<B>, area: Rect, state: &AppState) {
@@ -313,24 +576,23 @@ impl NetworkPlotter {
             return;
         }
 
-        // Prepare data for chart
-        let data: Vec<(f64, f64)> = state
+        // Prepare data for chart, keeping one sample before the window so
+        // the left edge can be interpolated rather than left to gap or snap
+        let raw_data: Vec<(f64, f64)> = state
             .packet_size_history
             .iter()
             .map(|point| (point.time, point.value))
             .collect();
 
         // Calculate x-axis boundaries
-        let x_min = if let Some(first) = state.packet_size_history.front() {
-            first.time
-        } else {
-            0.0
-        };
         let x_max = if let Some(last) = state.packet_size_history.back() {
             last.time
         } else {
             60.0
         };
+        let x_min = (x_max - state.window_size as f64).max(0.0);
+
+        let data = interpolate_left_edge(&raw_data, x_min);
 
         // Calculate y-axis boundaries
         let y_max = state
@@ -418,5 +680,409 @@ impl NetworkPlotter {
 
         f.render_widget(chart, area);
     }
+
+    fn render_traffic_chart<B: Backend>(&self, f: &mut Frame<B>, area: Rect, state: &AppState) {
+        if state.rx_history.is_empty() && state.tx_history.is_empty() {
+            let message = Paragraph::new("No RX/TX data available yet...").block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue))
+                    .title(Span::styled(
+                        "RX / TX Traffic",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            );
+            f.render_widget(message, area);
+            return;
+        }
+
+        let rx_data: Vec<(f64, f64)> = state
+            .rx_history
+            .iter()
+            .map(|point| (point.time, point.value))
+            .collect();
+        let tx_data: Vec<(f64, f64)> = state
+            .tx_history
+            .iter()
+            .map(|point| (point.time, point.value))
+            .collect();
+
+        // Calculate x-axis boundaries across both series
+        let x_min = state
+            .rx_history
+            .front()
+            .map(|p| p.time)
+            .into_iter()
+            .chain(state.tx_history.front().map(|p| p.time))
+            .fold(f64::INFINITY, f64::min);
+        let x_max = state
+            .rx_history
+            .back()
+            .map(|p| p.time)
+            .into_iter()
+            .chain(state.tx_history.back().map(|p| p.time))
+            .fold(0.0_f64, f64::max);
+
+        // Calculate y-axis boundaries across both series
+        let y_max = state
+            .rx_history
+            .iter()
+            .chain(state.tx_history.iter())
+            .map(|point| point.value)
+            .fold(1.0_f64, |max_val: f64, val| max_val.max(val))
+            * 1.2;
+
+        let datasets = vec![
+            Dataset::default()
+                .name("RX (bytes)")
+                .marker(symbols::Marker::Braille)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(Style::default().fg(Color::LightGreen))
+                .data(&rx_data),
+            Dataset::default()
+                .name("TX (bytes)")
+                .marker(symbols::Marker::Braille)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(Style::default().fg(Color::LightRed))
+                .data(&tx_data),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "RX / TX Traffic",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            )
+            .x_axis(
+                Axis::default()
+                    .title(Span::styled(
+                        "Time (s)",
+                        Style::default()
+                            .fg(Color::LightRed)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([x_min, x_max])
+                    .labels(
+                        [
+                            Span::styled(
+                                format!("{:.0}", x_min),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", (x_min + x_max) / 2.0),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", x_max),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                        ]
+                        .to_vec(),
+                    ),
+            )
+            .y_axis(
+                Axis::default()
+                    .title(Span::styled(
+                        "Bytes",
+                        Style::default()
+                            .fg(Color::LightGreen)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(
+                        [
+                            Span::styled("0", Style::default().fg(Color::Yellow)),
+                            Span::styled(
+                                format!("{:.0}", y_max / 2.0),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", y_max),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                        ]
+                        .to_vec(),
+                    ),
+            );
+
+        f.render_widget(chart, area);
+    }
+
+    fn render_throughput_chart<B: Backend>(&self, f: &mut Frame<B>, area: Rect, state: &AppState) {
+        if state.throughput_history.is_empty() {
+            let message = Paragraph::new("No data available yet...").block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue))
+                    .title(Span::styled(
+                        "Throughput",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            );
+            f.render_widget(message, area);
+            return;
+        }
+
+        let raw_data: Vec<(f64, f64)> = state
+            .throughput_history
+            .iter()
+            .map(|point| (point.time, point.value))
+            .collect();
+
+        let x_max = state
+            .throughput_history
+            .back()
+            .map(|p| p.time)
+            .unwrap_or(60.0);
+        let x_min = (x_max - state.window_size as f64).max(0.0);
+
+        let data = interpolate_left_edge(&raw_data, x_min);
+
+        let y_max = state
+            .throughput_history
+            .iter()
+            .map(|point| point.value)
+            .fold(1.0_f64, |max_val: f64, val| max_val.max(val))
+            * 1.2;
+
+        let datasets = vec![
+            Dataset::default()
+                .name("Throughput (KB/s)")
+                .marker(symbols::Marker::Braille)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(Style::default().fg(Color::LightYellow))
+                .data(&data),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Throughput Over Time",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            )
+            .x_axis(
+                Axis::default()
+                    .title(Span::styled(
+                        "Time (s)",
+                        Style::default()
+                            .fg(Color::LightRed)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([x_min, x_max])
+                    .labels(
+                        [
+                            Span::styled(
+                                format!("{:.0}", x_min),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", (x_min + x_max) / 2.0),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", x_max),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                        ]
+                        .to_vec(),
+                    ),
+            )
+            .y_axis(
+                Axis::default()
+                    .title(Span::styled(
+                        "KB/s",
+                        Style::default()
+                            .fg(Color::LightGreen)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(
+                        [
+                            Span::styled("0", Style::default().fg(Color::Yellow)),
+                            Span::styled(
+                                format!("{:.0}", y_max / 2.0),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{:.0}", y_max),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                        ]
+                        .to_vec(),
+                    ),
+            );
+
+        f.render_widget(chart, area);
+    }
+
+    fn render_protocol_table<B: Backend>(&self, f: &mut Frame<B>, area: Rect, state: &AppState) {
+        let total_packets = state.protocol_stats.total_packets();
+
+        let mut rows: Vec<(Protocol, usize, usize)> = state
+            .protocol_stats
+            .counts
+            .iter()
+            .map(|(&protocol, &(packets, bytes))| (protocol, packets, bytes))
+            .collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let header = Row::new(vec!["Protocol", "Packets", "Bytes", "%"]).style(
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let table_rows: Vec<Row> = rows
+            .into_iter()
+            .map(|(protocol, packets, bytes)| {
+                let pct = if total_packets > 0 {
+                    (packets as f64 / total_packets as f64) * 100.0
+                } else {
+                    0.0
+                };
+                Row::new(vec![
+                    protocol.label().to_string(),
+                    packets.to_string(),
+                    bytes.to_string(),
+                    format!("{:.1}%", pct),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(table_rows)
+            .header(header)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Protocols",
+                        Style::default()
+                            .fg(Color::LightCyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .widths(&[
+                Constraint::Percentage(30),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(20),
+            ]);
+
+        f.render_widget(table, area);
+    }
+
+    /// Render the top 5 IPs by bytes seen (as either source or destination).
+    fn render_top_talkers<B: Backend>(&self, f: &mut Frame<B>, area: Rect, state: &AppState) {
+        let mut talkers: Vec<(IpAddr, usize)> = state
+            .protocol_stats
+            .top_talkers
+            .iter()
+            .map(|(&ip, &bytes)| (ip, bytes))
+            .collect();
+        talkers.sort_by(|a, b| b.1.cmp(&a.1));
+        talkers.truncate(5);
+
+        let header = Row::new(vec!["IP", "Bytes"]).style(
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let table_rows: Vec<Row> = talkers
+            .into_iter()
+            .map(|(ip, bytes)| {
+                let (value, unit) = format_bytes(bytes as f64);
+                Row::new(vec![ip.to_string(), format!("{:.2} {}", value, unit)])
+            })
+            .collect();
+
+        let table = Table::new(table_rows)
+            .header(header)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Top Talkers",
+                        Style::default()
+                            .fg(Color::LightCyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .widths(&[Constraint::Percentage(60), Constraint::Percentage(40)]);
+
+        f.render_widget(table, area);
+    }
+}
+
+/// Format a byte count using whichever of MB/GB reads best, matching the
+/// adaptive units shown in the stat boxes.
+fn format_bytes(bytes: f64) -> (f64, &'static str) {
+    if bytes >= 1024.0 * 1024.0 * 1024.0 {
+        (bytes / (1024.0 * 1024.0 * 1024.0), "GB")
+    } else {
+        (bytes / (1024.0 * 1024.0), "MB")
+    }
+}
+
+/// Format a bytes/sec rate using whichever of B/s, KB/s, MB/s reads best.
+fn format_rate(bytes_per_sec: f64) -> (f64, &'static str) {
+    if bytes_per_sec >= 1024.0 * 1024.0 {
+        (bytes_per_sec / (1024.0 * 1024.0), "MB/s")
+    } else if bytes_per_sec >= 1024.0 {
+        (bytes_per_sec / 1024.0, "KB/s")
+    } else {
+        (bytes_per_sec, "B/s")
+    }
+}
+
+/// Synthesize a boundary point at `x_min` by linearly interpolating between
+/// the two samples that straddle it, so a chart's line stays anchored to the
+/// y-axis instead of showing a gap or snapping as data scrolls past the left
+/// edge. Falls back to `data` unchanged when no sample lies before the
+/// boundary. When the straddling samples share the same x, no boundary point
+/// is synthesized and only the samples from the boundary on are returned.
+fn interpolate_left_edge(data: &[(f64, f64)], x_min: f64) -> Vec<(f64, f64)> {
+    let Some(split) = data.iter().position(|&(x, _)| x >= x_min) else {
+        return data.to_vec();
+    };
+
+    if split == 0 {
+        return data.to_vec();
+    }
+
+    let (x0, y0) = data[split - 1];
+    let (x1, y1) = data[split];
+
+    if x1 == x0 {
+        return data[split..].to_vec();
+    }
+
+    let y_boundary = y0 + (y1 - y0) * (x_min - x0) / (x1 - x0);
+    let mut result = Vec::with_capacity(data.len() - split + 1);
+    result.push((x_min, y_boundary));
+    result.extend_from_slice(&data[split..]);
+    result
 }
 